@@ -1,6 +1,7 @@
 use wasm_bindgen::prelude::*;
 use js_sys::Float32Array;
 use std::f64::consts::PI;
+use wgpu::util::DeviceExt;
 
 // Set up wee_alloc as the global allocator for smaller WASM size
 #[global_allocator]
@@ -27,21 +28,42 @@ pub struct GlassState {
     drag_start_y: f64,
     initial_position_x: f64,
     initial_position_y: f64,
-    
+
     // Mouse state
     mouse_x: f64,
     mouse_y: f64,
     is_dragging: bool,
-    
+
     // Viewport constraints
     viewport_width: f64,
     viewport_height: f64,
     glass_width: f64,
     glass_height: f64,
     offset: f64,
-    
+
     // Animation state
     time: f64,
+
+    // Glass silhouette
+    shape: GlassShape,
+
+    // Liquid turbulence
+    noise_octaves: u32,
+    noise_frequency: f64,
+
+    // Throw-and-bounce physics
+    velocity_x: f64,
+    velocity_y: f64,
+    prev_drag_x: f64,
+    prev_drag_y: f64,
+    friction: f64,
+    restitution: f64,
+
+    // 3D tilt
+    yaw: f64,
+    pitch: f64,
+    tilt_sensitivity: f64,
+    is_hovering: bool,
 }
 
 #[wasm_bindgen]
@@ -64,9 +86,162 @@ impl GlassState {
             glass_height,
             offset: 10.0,
             time: 0.0,
+            shape: GlassShape::RoundedRect { half_width: 0.32, half_height: 0.22, radius: 0.6 },
+            noise_octaves: 4,
+            noise_frequency: 1.0,
+            velocity_x: 0.0,
+            velocity_y: 0.0,
+            prev_drag_x: 0.0,
+            prev_drag_y: 0.0,
+            friction: 0.05,
+            restitution: 0.6,
+            yaw: 0.0,
+            pitch: 0.0,
+            tilt_sensitivity: 30.0,
+            is_hovering: false,
         }
     }
-    
+
+    // Mark whether the cursor is over the panel; tilt eases back to level
+    // on the next `step_tilt` once this goes false
+    #[wasm_bindgen]
+    pub fn set_hovering(&mut self, hovering: bool) {
+        self.is_hovering = hovering;
+    }
+
+    // Ease yaw/pitch toward the cursor offset from the panel center while
+    // hovering, like a flycam mapping mouse delta to angle; spring back to
+    // level once the cursor leaves.
+    #[wasm_bindgen]
+    pub fn step_tilt(&mut self, delta_time: f64) {
+        let (target_yaw, target_pitch, ease_rate) = if self.is_hovering {
+            let offset_x = self.mouse_x - 0.5;
+            let offset_y = self.mouse_y - 0.5;
+            (offset_x * self.tilt_sensitivity, -offset_y * self.tilt_sensitivity, 10.0)
+        } else {
+            (0.0, 0.0, 6.0)
+        };
+
+        let ease = (delta_time * ease_rate).max(0.0).min(1.0);
+        self.yaw += (target_yaw - self.yaw) * ease;
+        self.pitch += (target_pitch - self.pitch) * ease;
+    }
+
+    // Setting yaw/pitch directly overrides `step_tilt` until the next hover
+    // update or spring-return moves them again
+    #[wasm_bindgen]
+    pub fn set_yaw(&mut self, yaw: f64) {
+        self.yaw = yaw;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_yaw(&self) -> f64 { self.yaw }
+
+    #[wasm_bindgen]
+    pub fn set_pitch(&mut self, pitch: f64) {
+        self.pitch = pitch;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_pitch(&self) -> f64 { self.pitch }
+
+    #[wasm_bindgen]
+    pub fn set_tilt_sensitivity(&mut self, sensitivity: f64) {
+        self.tilt_sensitivity = sensitivity;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_tilt_sensitivity(&self) -> f64 { self.tilt_sensitivity }
+
+    // Tune the liquid turbulence
+    #[wasm_bindgen]
+    pub fn set_noise_octaves(&mut self, octaves: u32) {
+        self.noise_octaves = octaves.max(1);
+    }
+
+    #[wasm_bindgen]
+    pub fn get_noise_octaves(&self) -> u32 {
+        self.noise_octaves
+    }
+
+    #[wasm_bindgen]
+    pub fn set_noise_frequency(&mut self, frequency: f64) {
+        self.noise_frequency = frequency;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_noise_frequency(&self) -> f64 {
+        self.noise_frequency
+    }
+
+    // Pick a simple glass silhouette
+    #[wasm_bindgen]
+    pub fn set_shape_circle(&mut self, radius: f64) {
+        self.shape = GlassShape::Circle { radius };
+    }
+
+    #[wasm_bindgen]
+    pub fn set_shape_ellipse(&mut self, half_width: f64, half_height: f64) {
+        self.shape = GlassShape::Ellipse { half_width, half_height };
+    }
+
+    #[wasm_bindgen]
+    pub fn set_shape_rounded_rect(&mut self, half_width: f64, half_height: f64, radius: f64) {
+        self.shape = GlassShape::RoundedRect { half_width, half_height, radius };
+    }
+
+    #[wasm_bindgen]
+    pub fn set_shape_regular_polygon(&mut self, sides: u32, radius: f64) {
+        self.shape = GlassShape::RegularPolygon { sides, radius };
+    }
+
+    // Hard union (min) of the current silhouette with another shape, described
+    // the same way as the `set_shape_*` setters: kind plus up to three params
+    // ("circle": a=radius; "ellipse": a=half_width, b=half_height;
+    // "rounded_rect": a=half_width, b=half_height, c=radius;
+    // "regular_polygon": a=sides, b=radius).
+    #[wasm_bindgen]
+    pub fn union_shape(&mut self, kind: &str, a: f64, b: f64, c: f64) {
+        let current = std::mem::replace(&mut self.shape, GlassShape::Circle { radius: 0.0 });
+        self.shape = GlassShape::Union(Box::new(current), Box::new(Self::shape_from_params(kind, a, b, c)));
+    }
+
+    // Hard intersection (max) of the current silhouette with another shape
+    #[wasm_bindgen]
+    pub fn intersect_shape(&mut self, kind: &str, a: f64, b: f64, c: f64) {
+        let current = std::mem::replace(&mut self.shape, GlassShape::Circle { radius: 0.0 });
+        self.shape = GlassShape::Intersection(Box::new(current), Box::new(Self::shape_from_params(kind, a, b, c)));
+    }
+
+    // Cut another shape out of the current silhouette
+    #[wasm_bindgen]
+    pub fn subtract_shape(&mut self, kind: &str, a: f64, b: f64, c: f64) {
+        let current = std::mem::replace(&mut self.shape, GlassShape::Circle { radius: 0.0 });
+        self.shape = GlassShape::Subtraction(Box::new(current), Box::new(Self::shape_from_params(kind, a, b, c)));
+    }
+
+    // Blend the current silhouette with another shape using a smooth union
+    #[wasm_bindgen]
+    pub fn smooth_union_shape(&mut self, kind: &str, a: f64, b: f64, c: f64, smoothing: f64) {
+        let current = std::mem::replace(&mut self.shape, GlassShape::Circle { radius: 0.0 });
+        self.shape = GlassShape::SmoothUnion(
+            Box::new(current),
+            Box::new(Self::shape_from_params(kind, a, b, c)),
+            smoothing,
+        );
+    }
+
+    // Builds a `GlassShape` from the same (kind, params) shorthand the
+    // `set_shape_*` setters and shape combinators use
+    fn shape_from_params(kind: &str, a: f64, b: f64, c: f64) -> GlassShape {
+        match kind {
+            "ellipse" => GlassShape::Ellipse { half_width: a, half_height: b },
+            "rounded_rect" => GlassShape::RoundedRect { half_width: a, half_height: b, radius: c },
+            "regular_polygon" => GlassShape::RegularPolygon { sides: a.max(3.0) as u32, radius: b },
+            _ => GlassShape::Circle { radius: a },
+        }
+    }
+
     // Update viewport size
     #[wasm_bindgen]
     pub fn update_viewport(&mut self, width: f64, height: f64) {
@@ -83,27 +258,77 @@ impl GlassState {
         self.drag_start_y = mouse_y;
         self.initial_position_x = self.position_x;
         self.initial_position_y = self.position_y;
+        self.prev_drag_x = mouse_x;
+        self.prev_drag_y = mouse_y;
+        self.velocity_x = 0.0;
+        self.velocity_y = 0.0;
     }
-    
-    // Update drag position
+
+    // Update drag position, tracking velocity from the motion since the last call
     #[wasm_bindgen]
-    pub fn update_drag(&mut self, mouse_x: f64, mouse_y: f64) {
+    pub fn update_drag(&mut self, mouse_x: f64, mouse_y: f64, delta_time: f64) {
         if self.is_dragging {
             let delta_x = mouse_x - self.drag_start_x;
             let delta_y = mouse_y - self.drag_start_y;
-            
+
             self.position_x = self.initial_position_x + delta_x;
             self.position_y = self.initial_position_y + delta_y;
-            
+
+            if delta_time > 0.0 {
+                self.velocity_x = (mouse_x - self.prev_drag_x) / delta_time;
+                self.velocity_y = (mouse_y - self.prev_drag_y) / delta_time;
+            }
+            self.prev_drag_x = mouse_x;
+            self.prev_drag_y = mouse_y;
+
             self.constrain_position();
         }
     }
-    
-    // Stop dragging
+
+    // Stop dragging, leaving the tracked velocity so the panel keeps moving
     #[wasm_bindgen]
     pub fn stop_drag(&mut self) {
         self.is_dragging = false;
     }
+
+    // Integrate one frame of throw-and-bounce motion
+    #[wasm_bindgen]
+    pub fn step_physics(&mut self, delta_time: f64) {
+        if self.is_dragging {
+            return;
+        }
+
+        self.position_x += self.velocity_x * delta_time;
+        self.position_y += self.velocity_y * delta_time;
+
+        let decay = self.friction.powf(delta_time);
+        self.velocity_x *= decay;
+        self.velocity_y *= decay;
+
+        self.constrain_position_with_bounce();
+    }
+
+    #[wasm_bindgen]
+    pub fn get_velocity_x(&self) -> f64 { self.velocity_x }
+
+    #[wasm_bindgen]
+    pub fn get_velocity_y(&self) -> f64 { self.velocity_y }
+
+    #[wasm_bindgen]
+    pub fn set_friction(&mut self, friction: f64) {
+        self.friction = friction;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_friction(&self) -> f64 { self.friction }
+
+    #[wasm_bindgen]
+    pub fn set_restitution(&mut self, restitution: f64) {
+        self.restitution = restitution;
+    }
+
+    #[wasm_bindgen]
+    pub fn get_restitution(&self) -> f64 { self.restitution }
     
     // Update mouse position for shader
     #[wasm_bindgen]
@@ -128,16 +353,220 @@ impl GlassState {
     #[wasm_bindgen]
     pub fn is_dragging(&self) -> bool { self.is_dragging }
     
-    // Constrain position within viewport
-    fn constrain_position(&mut self) {
+    // Viewport bounds as (min_x, max_x, min_y, max_y)
+    fn bounds(&self) -> (f64, f64, f64, f64) {
         let min_x = -self.viewport_width / 2.0 + self.glass_width / 2.0 + self.offset;
         let max_x = self.viewport_width / 2.0 - self.glass_width / 2.0 - self.offset;
         let min_y = -self.viewport_height / 2.0 + self.glass_height / 2.0 + self.offset;
         let max_y = self.viewport_height / 2.0 - self.glass_height / 2.0 - self.offset;
-        
+        (min_x, max_x, min_y, max_y)
+    }
+
+    // Constrain position within viewport
+    fn constrain_position(&mut self) {
+        let (min_x, max_x, min_y, max_y) = self.bounds();
+
         self.position_x = self.position_x.max(min_x).min(max_x);
         self.position_y = self.position_y.max(min_y).min(max_y);
     }
+
+    // Constrain position within viewport, reflecting velocity off whichever
+    // wall was hit so the panel bounces instead of just stopping at the edge
+    fn constrain_position_with_bounce(&mut self) {
+        let (min_x, max_x, min_y, max_y) = self.bounds();
+
+        if self.position_x < min_x {
+            self.position_x = min_x;
+            self.velocity_x = -self.velocity_x * self.restitution;
+        } else if self.position_x > max_x {
+            self.position_x = max_x;
+            self.velocity_x = -self.velocity_x * self.restitution;
+        }
+
+        if self.position_y < min_y {
+            self.position_y = min_y;
+            self.velocity_y = -self.velocity_y * self.restitution;
+        } else if self.position_y > max_y {
+            self.position_y = max_y;
+            self.velocity_y = -self.velocity_y * self.restitution;
+        }
+    }
+}
+
+// A group of glass panels that flock around each other instead of overlapping
+#[wasm_bindgen]
+pub struct GlassSwarm {
+    panels: Vec<GlassState>,
+    separation_radius: f64,
+    alignment_radius: f64,
+    cohesion_radius: f64,
+    separation_weight: f64,
+    alignment_weight: f64,
+    cohesion_weight: f64,
+    max_speed: f64,
+}
+
+#[wasm_bindgen]
+impl GlassSwarm {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GlassSwarm {
+        GlassSwarm {
+            panels: Vec::new(),
+            separation_radius: 120.0,
+            alignment_radius: 220.0,
+            cohesion_radius: 220.0,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+            max_speed: 400.0,
+        }
+    }
+
+    // Add a panel to the swarm, handing ownership to the group
+    #[wasm_bindgen]
+    pub fn add_panel(&mut self, panel: GlassState) {
+        self.panels.push(panel);
+    }
+
+    #[wasm_bindgen]
+    pub fn panel_count(&self) -> usize {
+        self.panels.len()
+    }
+
+    #[wasm_bindgen]
+    pub fn set_separation_radius(&mut self, radius: f64) { self.separation_radius = radius; }
+    #[wasm_bindgen]
+    pub fn get_separation_radius(&self) -> f64 { self.separation_radius }
+
+    #[wasm_bindgen]
+    pub fn set_alignment_radius(&mut self, radius: f64) { self.alignment_radius = radius; }
+    #[wasm_bindgen]
+    pub fn get_alignment_radius(&self) -> f64 { self.alignment_radius }
+
+    #[wasm_bindgen]
+    pub fn set_cohesion_radius(&mut self, radius: f64) { self.cohesion_radius = radius; }
+    #[wasm_bindgen]
+    pub fn get_cohesion_radius(&self) -> f64 { self.cohesion_radius }
+
+    #[wasm_bindgen]
+    pub fn set_separation_weight(&mut self, weight: f64) { self.separation_weight = weight; }
+    #[wasm_bindgen]
+    pub fn get_separation_weight(&self) -> f64 { self.separation_weight }
+
+    #[wasm_bindgen]
+    pub fn set_alignment_weight(&mut self, weight: f64) { self.alignment_weight = weight; }
+    #[wasm_bindgen]
+    pub fn get_alignment_weight(&self) -> f64 { self.alignment_weight }
+
+    #[wasm_bindgen]
+    pub fn set_cohesion_weight(&mut self, weight: f64) { self.cohesion_weight = weight; }
+    #[wasm_bindgen]
+    pub fn get_cohesion_weight(&self) -> f64 { self.cohesion_weight }
+
+    #[wasm_bindgen]
+    pub fn set_max_speed(&mut self, max_speed: f64) { self.max_speed = max_speed; }
+    #[wasm_bindgen]
+    pub fn get_max_speed(&self) -> f64 { self.max_speed }
+
+    // Advance one tick of Reynolds flocking: separation, alignment and cohesion
+    // combine into a steering acceleration per panel; the resulting velocity is
+    // clamped to max_speed and integrated into position, then clamped back
+    // on-screen like a single panel.
+    #[wasm_bindgen]
+    pub fn step_flocking(&mut self, delta_time: f64) {
+        let count = self.panels.len();
+        let positions: Vec<(f64, f64)> = self.panels.iter().map(|p| (p.position_x, p.position_y)).collect();
+        let velocities: Vec<(f64, f64)> = self.panels.iter().map(|p| (p.velocity_x, p.velocity_y)).collect();
+        let mut accelerations = vec![(0.0, 0.0); count];
+
+        for i in 0..count {
+            let (px, py) = positions[i];
+            let mut separation = (0.0, 0.0);
+            let mut alignment_sum = (0.0, 0.0);
+            let mut alignment_count = 0u32;
+            let mut cohesion_sum = (0.0, 0.0);
+            let mut cohesion_count = 0u32;
+
+            for j in 0..count {
+                if i == j {
+                    continue;
+                }
+                let (qx, qy) = positions[j];
+                let dx = px - qx;
+                let dy = py - qy;
+                let dist = length(dx, dy).max(1e-6);
+
+                if dist < self.separation_radius {
+                    separation.0 += dx / (dist * dist);
+                    separation.1 += dy / (dist * dist);
+                }
+                if dist < self.alignment_radius {
+                    alignment_sum.0 += velocities[j].0;
+                    alignment_sum.1 += velocities[j].1;
+                    alignment_count += 1;
+                }
+                if dist < self.cohesion_radius {
+                    cohesion_sum.0 += qx;
+                    cohesion_sum.1 += qy;
+                    cohesion_count += 1;
+                }
+            }
+
+            let mut accel = (separation.0 * self.separation_weight, separation.1 * self.separation_weight);
+
+            if alignment_count > 0 {
+                let avg_vx = alignment_sum.0 / alignment_count as f64;
+                let avg_vy = alignment_sum.1 / alignment_count as f64;
+                accel.0 += (avg_vx - velocities[i].0) * self.alignment_weight;
+                accel.1 += (avg_vy - velocities[i].1) * self.alignment_weight;
+            }
+
+            if cohesion_count > 0 {
+                let centroid_x = cohesion_sum.0 / cohesion_count as f64;
+                let centroid_y = cohesion_sum.1 / cohesion_count as f64;
+                accel.0 += (centroid_x - px) * self.cohesion_weight;
+                accel.1 += (centroid_y - py) * self.cohesion_weight;
+            }
+
+            accelerations[i] = accel;
+        }
+
+        for (i, panel) in self.panels.iter_mut().enumerate() {
+            panel.velocity_x += accelerations[i].0 * delta_time;
+            panel.velocity_y += accelerations[i].1 * delta_time;
+
+            let speed = length(panel.velocity_x, panel.velocity_y);
+            if speed > self.max_speed {
+                let scale = self.max_speed / speed;
+                panel.velocity_x *= scale;
+                panel.velocity_y *= scale;
+            }
+
+            panel.position_x += panel.velocity_x * delta_time;
+            panel.position_y += panel.velocity_y * delta_time;
+            panel.constrain_position();
+        }
+    }
+
+    // CSS transform strings for every panel, in insertion order
+    #[wasm_bindgen]
+    pub fn compute_all_transforms(&self) -> Vec<String> {
+        self.panels
+            .iter()
+            .map(|panel| {
+                calculate_transform_matrix(
+                    panel.position_x,
+                    panel.position_y,
+                    panel.glass_width,
+                    panel.glass_height,
+                    panel.yaw,
+                    panel.pitch,
+                    panel.is_dragging,
+                    panel.is_hovering,
+                )
+            })
+            .collect()
+    }
 }
 
 // Utility functions
@@ -160,48 +589,199 @@ fn rounded_rect_sdf(x: f64, y: f64, width: f64, height: f64, radius: f64) -> f64
 }
 
 #[inline]
-fn noise(x: f64, y: f64, time: f64) -> f64 {
-    let sin1 = (x * 8.0 + time).sin();
-    let cos1 = (y * 6.0 + time * 0.7).cos();
-    sin1 * cos1 * 0.08
+fn circle_sdf(x: f64, y: f64, radius: f64) -> f64 {
+    length(x, y) - radius
+}
+
+#[inline]
+fn ellipse_sdf(x: f64, y: f64, ax: f64, ay: f64) -> f64 {
+    (length(x / ax, y / ay) - 1.0) * ax.min(ay)
+}
+
+#[inline]
+fn regular_polygon_sdf(x: f64, y: f64, sides: u32, radius: f64) -> f64 {
+    let n = sides.max(3) as f64;
+    let angle = y.atan2(x);
+    let segment = 2.0 * PI / n;
+    let folded_angle = (angle / segment).round() * segment;
+    let cx = folded_angle.cos();
+    let cy = folded_angle.sin();
+    let apothem = radius * (PI / n).cos();
+    (x * cx + y * cy) - apothem
+}
+
+// SDF combinators
+#[inline]
+fn sdf_union(d1: f64, d2: f64) -> f64 {
+    d1.min(d2)
+}
+
+#[inline]
+fn sdf_intersection(d1: f64, d2: f64) -> f64 {
+    d1.max(d2)
+}
+
+#[inline]
+fn sdf_subtraction(d1: f64, d2: f64) -> f64 {
+    d1.max(-d2)
+}
+
+// Polynomial smooth minimum (https://iquilezles.org/articles/smin/)
+#[inline]
+fn smin(a: f64, b: f64, k: f64) -> f64 {
+    let h = (0.5 + 0.5 * (b - a) / k).max(0.0).min(1.0);
+    mix(b, a, h) - k * h * (1.0 - h)
+}
+
+#[inline]
+fn mix(a: f64, b: f64, t: f64) -> f64 {
+    a * (1.0 - t) + b * t
+}
+
+// Rotation matrix from Euler angles (yaw around Y, then pitch around X),
+// the same orientation model used to drive both the CSS rotateX/rotateY
+// and the shader's UV parallax so the two stay visually consistent.
+fn euler_rotation_matrix(yaw_deg: f64, pitch_deg: f64) -> [[f64; 3]; 3] {
+    let yaw = yaw_deg.to_radians();
+    let pitch = pitch_deg.to_radians();
+    let (sy, cy) = yaw.sin_cos();
+    let (sp, cp) = pitch.sin_cos();
+
+    [
+        [cy, sy * sp, sy * cp],
+        [0.0, cp, -sp],
+        [-sy, cy * sp, cy * cp],
+    ]
+}
+
+// Composable glass silhouette: a signed-distance shape that can be blended
+// with another one via the classic SDF boolean operations.
+#[derive(Clone)]
+enum GlassShape {
+    Circle { radius: f64 },
+    Ellipse { half_width: f64, half_height: f64 },
+    RoundedRect { half_width: f64, half_height: f64, radius: f64 },
+    RegularPolygon { sides: u32, radius: f64 },
+    Union(Box<GlassShape>, Box<GlassShape>),
+    Intersection(Box<GlassShape>, Box<GlassShape>),
+    Subtraction(Box<GlassShape>, Box<GlassShape>),
+    SmoothUnion(Box<GlassShape>, Box<GlassShape>, f64),
+}
+
+impl GlassShape {
+    fn signed_distance(&self, x: f64, y: f64) -> f64 {
+        match self {
+            GlassShape::Circle { radius } => circle_sdf(x, y, *radius),
+            GlassShape::Ellipse { half_width, half_height } => {
+                ellipse_sdf(x, y, *half_width, *half_height)
+            }
+            GlassShape::RoundedRect { half_width, half_height, radius } => {
+                rounded_rect_sdf(x, y, *half_width, *half_height, *radius)
+            }
+            GlassShape::RegularPolygon { sides, radius } => {
+                regular_polygon_sdf(x, y, *sides, *radius)
+            }
+            GlassShape::Union(a, b) => {
+                sdf_union(a.signed_distance(x, y), b.signed_distance(x, y))
+            }
+            GlassShape::Intersection(a, b) => {
+                sdf_intersection(a.signed_distance(x, y), b.signed_distance(x, y))
+            }
+            GlassShape::Subtraction(a, b) => {
+                sdf_subtraction(a.signed_distance(x, y), b.signed_distance(x, y))
+            }
+            GlassShape::SmoothUnion(a, b, k) => {
+                smin(a.signed_distance(x, y), b.signed_distance(x, y), *k)
+            }
+        }
+    }
+}
+
+// Deterministic lattice hash, folded into [0, 1)
+#[inline]
+fn hash(cell_x: f64, cell_y: f64, cell_z: f64) -> f64 {
+    let dot = cell_x * 127.1 + cell_y * 311.7 + cell_z * 74.7;
+    (dot.sin() * 43758.5453).rem_euclid(1.0)
+}
+
+// Value noise: bilinear interpolation of the four lattice corners surrounding
+// (x, y), faded with the smoothstep curve so corners blend without creases.
+// `z` offsets the lattice so time can be threaded through as a third dimension.
+#[inline]
+fn value_noise(x: f64, y: f64, z: f64) -> f64 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let fx = x - x0;
+    let fy = y - y0;
+
+    let c00 = hash(x0, y0, z);
+    let c10 = hash(x0 + 1.0, y0, z);
+    let c01 = hash(x0, y0 + 1.0, z);
+    let c11 = hash(x0 + 1.0, y0 + 1.0, z);
+
+    let tx = fx * fx * (3.0 - 2.0 * fx);
+    let ty = fy * fy * (3.0 - 2.0 * fy);
+
+    let top = mix(c00, c10, tx);
+    let bottom = mix(c01, c11, tx);
+    mix(top, bottom, ty) * 2.0 - 1.0
+}
+
+// Fractional Brownian motion: sum octaves of value noise, halving amplitude
+// and doubling frequency each octave (lacunarity 2.0, gain 0.5).
+#[inline]
+fn fbm(x: f64, y: f64, time: f64, octaves: u32) -> f64 {
+    let mut amplitude = 0.5;
+    let mut frequency = 1.0;
+    let mut sum = 0.0;
+
+    for _ in 0..octaves {
+        sum += value_noise(x * frequency, y * frequency, time) * amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+
+    sum
 }
 
 // Optimized fragment shader calculation
 #[inline]
-fn fragment_shader(
-    uv_x: f64, 
-    uv_y: f64, 
-    mouse_x: f64, 
-    mouse_y: f64, 
-    time: f64
-) -> (f64, f64) {
+fn fragment_shader(uv_x: f64, uv_y: f64, state: &GlassState) -> (f64, f64) {
     let ix = uv_x - 0.5;
     let iy = uv_y - 0.5;
-    
+    let time = state.time;
+
     // Enhanced SDF with 3D perspective
-    let distance_to_edge = rounded_rect_sdf(ix, iy, 0.32, 0.22, 0.6);
-    
+    let distance_to_edge = state.shape.signed_distance(ix, iy);
+
     // Optimized mouse interaction
-    let mouse_influence = length(ix - (mouse_x - 0.5), iy - (mouse_y - 0.5));
+    let mouse_influence = length(ix - (state.mouse_x - 0.5), iy - (state.mouse_y - 0.5));
     let mouse_effect = smooth_step(0.25, 0.0, mouse_influence) * 0.6;
-    
-    // Simplified organic movement
-    let organic_x = noise(ix * 1.5, iy * 1.5, time * 1.5) * 0.25;
-    let organic_y = noise(ix * 1.2, iy * 1.8, time * 1.2) * 0.25;
-    
+
+    // Organic movement driven by fractional Brownian motion
+    let freq = state.noise_frequency;
+    let octaves = state.noise_octaves;
+    let organic_x = fbm(ix * 1.5 * freq, iy * 1.5 * freq, time * 1.5, octaves) * 0.25;
+    let organic_y = fbm(ix * 1.2 * freq, iy * 1.8 * freq, time * 1.2, octaves) * 0.25;
+
     // Enhanced displacement calculation
     let displacement = smooth_step(0.85, 0.0, distance_to_edge - 0.08);
     let scaled = smooth_step(0.0, 1.0, displacement * (1.0 + mouse_effect));
-    
-    // Simplified 3D perspective transformation
+
+    // Fixed vertical perspective term, plus parallax driven by the real
+    // yaw/pitch orientation
     let perspective = 1.0 + (iy * 0.15);
-    let rotation_x = (time * 0.4).cos() * 0.015;
-    let rotation_y = (time * 0.25).sin() * 0.015;
-    
+    let rotation = euler_rotation_matrix(state.yaw, state.pitch);
+    let depth = 0.25;
+    let warped_x = rotation[0][0] * ix + rotation[0][1] * iy + rotation[0][2] * depth;
+    let warped_y = rotation[1][0] * ix + rotation[1][1] * iy + rotation[1][2] * depth;
+    let parallax_x = (warped_x - ix) * 0.5;
+    let parallax_y = (warped_y - iy) * 0.5;
+
     // Combined transformation
-    let final_x = ix * scaled * perspective + organic_x + rotation_x + 0.5;
-    let final_y = iy * scaled * perspective + organic_y + rotation_y + 0.5;
-    
+    let final_x = ix * scaled * perspective + organic_x + parallax_x + 0.5;
+    let final_y = iy * scaled * perspective + organic_y + parallax_y + 0.5;
+
     (final_x, final_y)
 }
 
@@ -212,22 +792,26 @@ pub fn calculate_transform_matrix(
     position_y: f64,
     glass_width: f64,
     glass_height: f64,
+    yaw: f64,
+    pitch: f64,
     is_dragging: bool,
     is_hovering: bool
 ) -> String {
     let perspective = "perspective(1500px)";
-    let translate = format!("translate3d({}px, {}px, 0)", 
-        position_x - glass_width / 2.0, 
+    let translate = format!("translate3d({}px, {}px, 0)",
+        position_x - glass_width / 2.0,
         position_y - glass_height / 2.0);
-    
-    let transform = if is_dragging {
-        "rotateX(3deg) rotateY(-2deg) scale(0.995)"
+
+    let scale = if is_dragging {
+        0.995
     } else if is_hovering {
-        "rotateX(3deg) rotateY(-2deg) scale(1.02)"
+        1.02
     } else {
-        "rotateX(2deg) rotateY(-1deg)"
+        1.0
     };
-    
+
+    let transform = format!("rotateX({:.3}deg) rotateY({:.3}deg) scale({})", pitch, -yaw, scale);
+
     format!("{} {} {}", perspective, translate, transform)
 }
 
@@ -253,11 +837,7 @@ pub fn compute_shader_with_state(
             let uv_x = x as f64 / w as f64;
             let uv_y = y as f64 / h as f64;
             
-            let (final_x, final_y) = fragment_shader(
-                uv_x, uv_y, 
-                state.mouse_x, state.mouse_y, 
-                state.time
-            );
+            let (final_x, final_y) = fragment_shader(uv_x, uv_y, state);
             
             let dx = final_x * w as f64 - x as f64;
             let dy = final_y * h as f64 - y as f64;
@@ -293,6 +873,379 @@ pub fn compute_shader_with_state(
     max_scale
 }
 
+// WGSL port of `fragment_shader`: SDF distance, mouse influence and
+// perspective, dispatched in 8x8 workgroups over the displacement map.
+// Only the rounded-rect silhouette is ported for now, matching the default
+// `GlassShape`; other shapes still render through the CPU path.
+const DISPLACEMENT_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    mouse_x: f32,
+    mouse_y: f32,
+    time: f32,
+    rect_half_width: f32,
+    rect_half_height: f32,
+    rect_radius: f32,
+    yaw: f32,
+    pitch: f32,
+    noise_frequency: f32,
+    noise_octaves: u32,
+};
+
+@group(0) @binding(0) var<uniform> params: Params;
+// rg32float isn't storage-capable in core WebGPU; rgba32float is, so the
+// displacement is written into the RG channels and BA are left unused.
+@group(0) @binding(1) var output: texture_storage_2d<rgba32float, write>;
+
+fn smooth_step(a: f32, b: f32, t: f32) -> f32 {
+    let u = clamp((t - a) / (b - a), 0.0, 1.0);
+    return u * u * (3.0 - 2.0 * u);
+}
+
+fn rounded_rect_sdf(p: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(p) - half_size + radius;
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0, 0.0))) - radius;
+}
+
+// Deterministic lattice hash, folded into [0, 1) — mirrors the CPU `hash`
+fn hash(cell: vec3<f32>) -> f32 {
+    let dot = cell.x * 127.1 + cell.y * 311.7 + cell.z * 74.7;
+    return fract(sin(dot) * 43758.5453);
+}
+
+// Bilinear value noise over the four lattice corners, faded with smoothstep
+fn value_noise(p: vec2<f32>, z: f32) -> f32 {
+    let i = floor(p);
+    let f = p - i;
+
+    let c00 = hash(vec3<f32>(i.x, i.y, z));
+    let c10 = hash(vec3<f32>(i.x + 1.0, i.y, z));
+    let c01 = hash(vec3<f32>(i.x, i.y + 1.0, z));
+    let c11 = hash(vec3<f32>(i.x + 1.0, i.y + 1.0, z));
+
+    let t = f * f * (3.0 - 2.0 * f);
+    let top = mix(c00, c10, t.x);
+    let bottom = mix(c01, c11, t.x);
+    return mix(top, bottom, t.y) * 2.0 - 1.0;
+}
+
+// Fractional Brownian motion: sum octaves of value noise (lacunarity 2.0, gain 0.5)
+fn fbm(p: vec2<f32>, time: f32, octaves: u32) -> f32 {
+    var amplitude = 0.5;
+    var frequency = 1.0;
+    var sum = 0.0;
+
+    for (var i = 0u; i < octaves; i = i + 1u) {
+        sum = sum + value_noise(p * frequency, time) * amplitude;
+        amplitude = amplitude * 0.5;
+        frequency = frequency * 2.0;
+    }
+
+    return sum;
+}
+
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if (id.x >= params.width || id.y >= params.height) {
+        return;
+    }
+
+    let uv = vec2<f32>(f32(id.x) / f32(params.width), f32(id.y) / f32(params.height));
+    let ip = uv - vec2<f32>(0.5, 0.5);
+
+    let distance_to_edge = rounded_rect_sdf(ip, vec2<f32>(params.rect_half_width, params.rect_half_height), params.rect_radius);
+
+    let mouse_influence = length(ip - (vec2<f32>(params.mouse_x, params.mouse_y) - vec2<f32>(0.5, 0.5)));
+    let mouse_effect = smooth_step(0.25, 0.0, mouse_influence) * 0.6;
+
+    let freq = params.noise_frequency;
+    let organic_x = fbm(ip * 1.5 * freq, params.time * 1.5, params.noise_octaves) * 0.25;
+    let organic_y = fbm(ip * vec2<f32>(1.2, 1.8) * freq, params.time * 1.2, params.noise_octaves) * 0.25;
+
+    let displacement = smooth_step(0.85, 0.0, distance_to_edge - 0.08);
+    let scaled = smooth_step(0.0, 1.0, displacement * (1.0 + mouse_effect));
+
+    let perspective = 1.0 + ip.y * 0.15;
+
+    // Euler yaw/pitch parallax, matching the CPU path's rotation matrix
+    let yaw = radians(params.yaw);
+    let pitch = radians(params.pitch);
+    let depth = 0.25;
+    let warped_x = cos(yaw) * ip.x + sin(yaw) * sin(pitch) * ip.y + sin(yaw) * cos(pitch) * depth;
+    let warped_y = cos(pitch) * ip.y - sin(pitch) * depth;
+    let parallax_x = (warped_x - ip.x) * 0.5;
+    let parallax_y = (warped_y - ip.y) * 0.5;
+
+    let final_x = ip.x * scaled * perspective + organic_x + parallax_x + 0.5;
+    let final_y = ip.y * scaled * perspective + organic_y + parallax_y + 0.5;
+
+    let dx = final_x * f32(params.width) - f32(id.x);
+    let dy = final_y * f32(params.height) - f32(id.y);
+
+    textureStore(output, vec2<i32>(i32(id.x), i32(id.y)), vec4<f32>(dx, dy, 0.0, 1.0));
+}
+"#;
+
+// Mirrors the WGSL `Params` uniform layout field-for-field
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct DisplacementParams {
+    width: u32,
+    height: u32,
+    mouse_x: f32,
+    mouse_y: f32,
+    time: f32,
+    rect_half_width: f32,
+    rect_half_height: f32,
+    rect_radius: f32,
+    yaw: f32,
+    pitch: f32,
+    noise_frequency: f32,
+    noise_octaves: u32,
+}
+
+// GPU-backed displacement renderer, falling back to the CPU path when the
+// browser has no WebGPU adapter available.
+#[wasm_bindgen]
+pub struct GpuGlassRenderer {
+    device: Option<wgpu::Device>,
+    queue: Option<wgpu::Queue>,
+    pipeline: Option<wgpu::ComputePipeline>,
+}
+
+#[wasm_bindgen]
+impl GpuGlassRenderer {
+    // Try to acquire a WebGPU adapter/device; falls back to the CPU path if
+    // the browser doesn't expose `navigator.gpu` or adapter/device creation fails.
+    // Not a `#[wasm_bindgen(constructor)]` since wasm-bindgen constructors can't
+    // be async — call as `await GpuGlassRenderer.create()` from JS instead.
+    #[wasm_bindgen(js_name = create)]
+    pub async fn create() -> GpuGlassRenderer {
+        match Self::try_init_gpu().await {
+            Ok((device, queue, pipeline)) => GpuGlassRenderer {
+                device: Some(device),
+                queue: Some(queue),
+                pipeline: Some(pipeline),
+            },
+            Err(err) => {
+                console_log!("WebGPU unavailable, falling back to CPU: {}", err);
+                GpuGlassRenderer { device: None, queue: None, pipeline: None }
+            }
+        }
+    }
+
+    async fn try_init_gpu() -> Result<(wgpu::Device, wgpu::Queue, wgpu::ComputePipeline), String> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::BROWSER_WEBGPU,
+            ..Default::default()
+        });
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .ok_or("no WebGPU adapter")?;
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("displacement"),
+            source: wgpu::ShaderSource::Wgsl(DISPLACEMENT_SHADER.into()),
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("displacement_pipeline"),
+            layout: None,
+            module: &shader,
+            entry_point: "main",
+        });
+
+        Ok((device, queue, pipeline))
+    }
+
+    #[wasm_bindgen]
+    pub fn is_gpu(&self) -> bool {
+        self.pipeline.is_some()
+    }
+
+    // Computes the RGBA displacement map, dispatching 8x8 workgroups on the
+    // GPU when available, otherwise falling back to `compute_shader_with_state`.
+    // Only `RoundedRect` is ported to WGSL so far; any other shape falls back
+    // to the CPU path per-call rather than silently rendering the wrong outline.
+    #[wasm_bindgen]
+    pub async fn render(&self, state: &GlassState, width: u32, height: u32) -> Vec<u8> {
+        let mut output = vec![0u8; (width * height * 4) as usize];
+        let gpu_supports_shape = matches!(&state.shape, GlassShape::RoundedRect { .. });
+
+        if gpu_supports_shape {
+            if let (Some(device), Some(queue), Some(pipeline)) = (&self.device, &self.queue, &self.pipeline) {
+                if let Ok(raw) = Self::dispatch(device, queue, pipeline, state, width, height).await {
+                    normalize_displacement_into(&raw, &mut output);
+                    return output;
+                }
+            }
+        }
+
+        compute_shader_with_state(state, width, height, &mut output);
+        output
+    }
+
+    // Uploads state, dispatches the compute shader in 8x8 workgroups and
+    // reads the storage texture back as interleaved (dx, dy) floats.
+    async fn dispatch(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        pipeline: &wgpu::ComputePipeline,
+        state: &GlassState,
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<f32>, String> {
+        // `render` only calls dispatch for `RoundedRect` shapes; anything else
+        // falls back to the CPU path before reaching here.
+        let (rect_half_width, rect_half_height, rect_radius) = match &state.shape {
+            GlassShape::RoundedRect { half_width, half_height, radius } => {
+                (*half_width as f32, *half_height as f32, *radius as f32)
+            }
+            _ => (0.5, 0.5, 0.0),
+        };
+
+        let params = DisplacementParams {
+            width,
+            height,
+            mouse_x: state.mouse_x as f32,
+            mouse_y: state.mouse_y as f32,
+            time: state.time as f32,
+            rect_half_width,
+            rect_half_height,
+            rect_radius,
+            yaw: state.yaw as f32,
+            pitch: state.pitch as f32,
+            noise_frequency: state.noise_frequency as f32,
+            noise_octaves: state.noise_octaves,
+        };
+
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("displacement_params"),
+            contents: bytemuck::bytes_of(&params),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("displacement_texture"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            // rg32float isn't a core storage-texture format; rgba32float is.
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group_layout = pipeline.get_bind_group_layout(0);
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("displacement_bind_group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: params_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&view) },
+            ],
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((width + 7) / 8, (height + 7) / 8, 1);
+        }
+
+        // Rgba32Float is 16 bytes/pixel; pad each row to wgpu's 256-byte alignment.
+        let unpadded_bytes_per_row = width * 16;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = (unpadded_bytes_per_row + align - 1) / align * align;
+
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("displacement_readback"),
+            size: (padded_bytes_per_row * height) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &readback_buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+        );
+
+        queue.submit(Some(encoder.finish()));
+
+        let slice = readback_buffer.slice(..);
+        let (sender, receiver) = futures_channel::oneshot::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver.await.map_err(|e| e.to_string())?.map_err(|e| e.to_string())?;
+
+        let data = slice.get_mapped_range();
+        let mut raw = Vec::with_capacity((width * height * 2) as usize);
+        for row in 0..height {
+            let start = (row * padded_bytes_per_row) as usize;
+            let row_bytes = &data[start..start + (unpadded_bytes_per_row as usize)];
+            let row_floats: &[f32] = bytemuck::cast_slice(row_bytes);
+            // Each pixel is (r=dx, g=dy, b, a); keep only the RG displacement.
+            for pixel in row_floats.chunks_exact(4) {
+                raw.push(pixel[0]);
+                raw.push(pixel[1]);
+            }
+        }
+        drop(data);
+        readback_buffer.unmap();
+
+        Ok(raw)
+    }
+}
+
+// Normalizes interleaved (dx, dy) pairs into the same RGBA8 encoding that
+// `compute_shader_with_state` writes on the CPU path.
+fn normalize_displacement_into(raw: &[f32], output: &mut [u8]) {
+    let mut max_scale = 0.0f32;
+    for v in raw {
+        max_scale = max_scale.max(v.abs());
+    }
+    max_scale *= 0.6;
+    if max_scale == 0.0 {
+        max_scale = 1.0;
+    }
+
+    let mut index = 0;
+    for i in (0..output.len()).step_by(4) {
+        if index + 1 < raw.len() {
+            let r = (raw[index] / max_scale + 0.5) * 255.0;
+            let g = (raw[index + 1] / max_scale + 0.5) * 255.0;
+
+            output[i] = r.max(0.0).min(255.0) as u8;
+            output[i + 1] = g.max(0.0).min(255.0) as u8;
+            output[i + 2] = 120;
+            output[i + 3] = 255;
+
+            index += 2;
+        }
+    }
+}
+
 // Utility functions for JavaScript
 #[wasm_bindgen]
 pub fn throttle_should_update(last_time: f64, current_time: f64, delay: f64) -> bool {
@@ -350,4 +1303,72 @@ impl PerformanceMonitor {
 pub fn main() {
     console_log!("🦀 Liquid Glass WASM Engine initialized!");
     console_log!("🚀 All calculations running in optimized Rust code");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_stays_in_unit_range_across_zero() {
+        // A naive `.fract()` goes negative whenever the scaled sine is negative;
+        // `rem_euclid` keeps cells on either side of 0 inside [0, 1).
+        let left = hash(-1.0, 0.0, 0.0);
+        let zero = hash(0.0, 0.0, 0.0);
+        let right = hash(1.0, 0.0, 0.0);
+        assert!((0.0..1.0).contains(&left));
+        assert!((0.0..1.0).contains(&zero));
+        assert!((0.0..1.0).contains(&right));
+    }
+
+    #[test]
+    fn value_noise_is_deterministic() {
+        let a = value_noise(1.23, 4.56, 0.0);
+        let b = value_noise(1.23, 4.56, 0.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn value_noise_is_continuous_at_lattice_corners() {
+        // Sampling exactly on a lattice point should equal the raw hash at
+        // that cell (folded the same way value_noise folds its output).
+        let corner = value_noise(2.0, 3.0, 0.0);
+        let expected = hash(2.0, 3.0, 0.0) * 2.0 - 1.0;
+        assert!((corner - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fbm_stays_in_a_bounded_range() {
+        for i in 0..20 {
+            let v = fbm(i as f64 * 0.37, i as f64 * 0.71, 0.0, 4);
+            assert!(v.abs() <= 1.0, "fbm({i}) = {v} out of range");
+        }
+    }
+
+    #[test]
+    fn smin_is_at_most_the_hard_min() {
+        assert!(smin(1.0, 2.0, 0.5) <= 1.0 + 1e-9);
+        assert!(smin(-1.0, 3.0, 0.5) <= -1.0 + 1e-9);
+    }
+
+    #[test]
+    fn smin_matches_hard_min_when_smoothing_is_negligible() {
+        let a = 1.0;
+        let b = 5.0;
+        assert!((smin(a, b, 1e-6) - a.min(b)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn circle_sdf_is_zero_on_the_boundary() {
+        assert!((circle_sdf(1.0, 0.0, 1.0)).abs() < 1e-9);
+        assert!(circle_sdf(0.0, 0.0, 1.0) < 0.0);
+        assert!(circle_sdf(2.0, 0.0, 1.0) > 0.0);
+    }
+
+    #[test]
+    fn sdf_combinators_match_their_definitions() {
+        assert_eq!(sdf_union(1.0, -2.0), -2.0);
+        assert_eq!(sdf_intersection(1.0, -2.0), 1.0);
+        assert_eq!(sdf_subtraction(1.0, -2.0), 2.0);
+    }
 } 
\ No newline at end of file